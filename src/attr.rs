@@ -0,0 +1,146 @@
+use crate::netlist::Netlist;
+
+/// A typed attribute value attached to a module or net.
+///
+/// EDA netlists carry many kinds of per-element data (cell type, area, fixed
+/// coordinate, timing, ...) that a single integer weight cannot represent,
+/// so attributes are stored as one of these variants instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i32),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl Netlist {
+    /// Sets the attribute `key` on `module` to `value`.
+    ///
+    /// The `"weight"` key is a convenience view backed by `module_weight`
+    /// rather than the general attribute map, so existing code that reads
+    /// `module_weight` directly keeps working.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::attr::Value;
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.set_module_attr("m1", "cell_type", Value::Str("NAND2".to_string()));
+    /// assert_eq!(
+    ///     netlist.get_module_attr("m1", "cell_type"),
+    ///     Some(Value::Str("NAND2".to_string()))
+    /// );
+    /// ```
+    ///
+    /// If `key` is `"weight"` and `value` isn't a `Value::Int`, the value
+    /// can't be represented by `module_weight` (an `i32` map), so it falls
+    /// back to being stored as a regular attribute instead of being dropped.
+    pub fn set_module_attr(&mut self, module: &str, key: &str, value: Value) {
+        if key == "weight" {
+            if let Value::Int(weight) = value {
+                self.module_weight
+                    .get_or_insert_with(std::collections::HashMap::new)
+                    .insert(module.to_string(), weight);
+                return;
+            }
+        }
+        self.module_attrs
+            .entry(module.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    /// Returns the attribute `key` on `module`, or `None` if unset.
+    ///
+    /// For `"weight"`, this checks `module_weight` first and falls back to
+    /// the general attribute map, so a non-`Value::Int` weight stashed there
+    /// by [`Netlist::set_module_attr`] is still found.
+    pub fn get_module_attr(&self, module: &str, key: &str) -> Option<Value> {
+        if key == "weight" {
+            if let Some(&weight) = self.module_weight.as_ref().and_then(|w| w.get(module)) {
+                return Some(Value::Int(weight));
+            }
+        }
+        self.module_attrs.get(module)?.get(key).cloned()
+    }
+
+    /// Sets the attribute `key` on `net` to `value`.
+    ///
+    /// The `"weight"` key is a convenience view backed by `net_weight`, as
+    /// with [`Netlist::set_module_attr`] — including the fallback to a
+    /// regular attribute when `value` isn't a `Value::Int`.
+    pub fn set_net_attr(&mut self, net: &str, key: &str, value: Value) {
+        if key == "weight" {
+            if let Value::Int(weight) = value {
+                self.net_weight
+                    .get_or_insert_with(std::collections::HashMap::new)
+                    .insert(net.to_string(), weight);
+                return;
+            }
+        }
+        self.net_attrs
+            .entry(net.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    /// Returns the attribute `key` on `net`, or `None` if unset, with the
+    /// same `"weight"` fallback behavior as [`Netlist::get_module_attr`].
+    pub fn get_net_attr(&self, net: &str, key: &str) -> Option<Value> {
+        if key == "weight" {
+            if let Some(&weight) = self.net_weight.as_ref().and_then(|w| w.get(net)) {
+                return Some(Value::Int(weight));
+            }
+        }
+        self.net_attrs.get(net)?.get(key).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_attr_roundtrip() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.set_module_attr("m1", "area", Value::Float(12.5));
+        netlist.set_module_attr("m1", "fixed", Value::Bool(true));
+        assert_eq!(netlist.get_module_attr("m1", "area"), Some(Value::Float(12.5)));
+        assert_eq!(netlist.get_module_attr("m1", "fixed"), Some(Value::Bool(true)));
+        assert_eq!(netlist.get_module_attr("m1", "missing"), None);
+    }
+
+    #[test]
+    fn test_weight_attr_is_view_of_module_weight() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.set_module_attr("m1", "weight", Value::Int(42));
+        assert_eq!(netlist.module_weight.as_ref().unwrap()["m1"], 42);
+        assert_eq!(netlist.get_module_attr("m1", "weight"), Some(Value::Int(42)));
+    }
+
+    #[test]
+    fn test_net_attr_roundtrip() {
+        let mut netlist = Netlist::new();
+        netlist.add_net("n1".to_string());
+        netlist.set_net_attr("n1", "weight", Value::Int(7));
+        assert_eq!(netlist.net_weight.as_ref().unwrap()["n1"], 7);
+        assert_eq!(netlist.get_net_attr("n1", "weight"), Some(Value::Int(7)));
+    }
+
+    #[test]
+    fn test_non_int_weight_falls_back_to_attribute_map() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.set_module_attr("m1", "weight", Value::Float(1.5));
+        assert_eq!(netlist.module_weight, None);
+        assert_eq!(
+            netlist.get_module_attr("m1", "weight"),
+            Some(Value::Float(1.5))
+        );
+    }
+}
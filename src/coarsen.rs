@@ -0,0 +1,246 @@
+use crate::netlist::Netlist;
+use std::collections::{HashMap, HashSet};
+
+/// A disjoint-set (union-find) structure over a fixed universe of `0..n` indices.
+///
+/// Supports the usual `find` with path compression and `join` (union) by rank,
+/// which keeps both operations close to amortized constant time.
+#[derive(Debug, Clone)]
+struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    /// Finds the representative of `x`, compressing the path as it goes.
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    /// Merges the sets containing `a` and `b`, attaching the lower-rank root
+    /// under the higher-rank one.
+    fn join(&mut self, a: usize, b: usize) {
+        let ra = self.find(a);
+        let rb = self.find(b);
+        if ra == rb {
+            return;
+        }
+        match self.rank[ra].cmp(&self.rank[rb]) {
+            std::cmp::Ordering::Less => self.parent[ra] = rb,
+            std::cmp::Ordering::Greater => self.parent[rb] = ra,
+            std::cmp::Ordering::Equal => {
+                self.parent[rb] = ra;
+                self.rank[ra] += 1;
+            }
+        }
+    }
+}
+
+impl Netlist {
+    fn weight_of_module(&self, module: &str) -> i32 {
+        self.module_weight
+            .as_ref()
+            .and_then(|w| w.get(module))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    fn weight_of_net(&self, net: &str) -> i32 {
+        self.net_weight
+            .as_ref()
+            .and_then(|w| w.get(net))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    /// Coarsens the netlist by one level using union-find heavy-edge matching.
+    ///
+    /// Nets are visited in increasing degree order. Within each net, the pair
+    /// of still-unmatched incident modules maximizing combined `module_weight`
+    /// over the net's weight is joined in a disjoint-set, and this repeats
+    /// until fewer than two unmatched incident modules remain. Once every net has
+    /// been processed, each disjoint-set root becomes one super-module in the
+    /// returned `Netlist`, whose `module_weight` is the sum of its members.
+    /// Nets wholly contained in a single cluster are dropped; the rest are
+    /// rewired to the super-modules, with parallel incidences deduplicated.
+    ///
+    /// The second element of the returned tuple maps each original module
+    /// index (in `self.modules`) to its cluster index in the coarse netlist,
+    /// which a caller can use to uncoarsen a partition assignment. Calling
+    /// `coarsen` repeatedly on the result builds a coarsening hierarchy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m0".to_string());
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_net("n0".to_string());
+    /// netlist.add_edge("n0", "m0");
+    /// netlist.add_edge("n0", "m1");
+    ///
+    /// let (coarse, cluster_of) = netlist.coarsen();
+    /// assert_eq!(coarse.num_modules, 1);
+    /// assert_eq!(cluster_of, vec![0, 0]);
+    /// ```
+    pub fn coarsen(&self) -> (Netlist, Vec<usize>) {
+        let n = self.num_modules;
+        let mut dsu = DisjointSet::new(n);
+        let module_index: HashMap<&str, usize> = self
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.as_str(), i))
+            .collect();
+
+        let mut net_order: Vec<&String> = self.nets.iter().collect();
+        net_order.sort_by_key(|net| self.net_degree(net));
+
+        let mut matched: HashSet<usize> = HashSet::new();
+        for net in net_order {
+            let net_w = self.weight_of_net(net);
+            let mut incident: Vec<usize> = self
+                .modules_of_net(net)
+                .filter_map(|m| module_index.get(m).copied())
+                .filter(|idx| !matched.contains(idx))
+                .collect();
+
+            while incident.len() >= 2 {
+                let mut best_pair = (0, 1);
+                let mut best_score = f64::MIN;
+                for i in 0..incident.len() {
+                    for j in (i + 1)..incident.len() {
+                        let wa = self.weight_of_module(&self.modules[incident[i]]);
+                        let wb = self.weight_of_module(&self.modules[incident[j]]);
+                        let score = (wa + wb) as f64 / net_w as f64;
+                        if score > best_score {
+                            best_score = score;
+                            best_pair = (i, j);
+                        }
+                    }
+                }
+                let (i, j) = best_pair;
+                let a = incident[i];
+                let b = incident[j];
+                dsu.join(a, b);
+                matched.insert(a);
+                matched.insert(b);
+                incident.remove(j);
+                incident.remove(i);
+            }
+        }
+
+        let roots: Vec<usize> = (0..n).map(|i| dsu.find(i)).collect();
+        let mut cluster_id_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut cluster_of: Vec<usize> = Vec::with_capacity(n);
+        for &root in &roots {
+            let next_id = cluster_id_of_root.len();
+            let id = *cluster_id_of_root.entry(root).or_insert(next_id);
+            cluster_of.push(id);
+        }
+        let num_clusters = cluster_id_of_root.len();
+
+        let mut coarse = Netlist::new();
+        coarse.num_pads = self.num_pads;
+        coarse.cost_model = self.cost_model;
+        let mut module_weight: HashMap<String, i32> = HashMap::new();
+        for cluster in 0..num_clusters {
+            let name = format!("c{cluster}");
+            coarse.add_module(name.clone());
+            if self.module_fixed.iter().any(|m| {
+                module_index
+                    .get(m.as_str())
+                    .map(|&idx| cluster_of[idx] == cluster)
+                    .unwrap_or(false)
+            }) {
+                coarse.module_fixed.insert(name.clone());
+            }
+            *module_weight.entry(name).or_insert(0) = 0;
+        }
+        for (idx, module) in self.modules.iter().enumerate() {
+            let name = format!("c{}", cluster_of[idx]);
+            *module_weight.entry(name).or_insert(0) += self.weight_of_module(module);
+        }
+        coarse.module_weight = Some(module_weight);
+
+        let mut net_weight: HashMap<String, i32> = HashMap::new();
+        for net in &self.nets {
+            let mut clusters: Vec<usize> = self
+                .modules_of_net(net)
+                .filter_map(|m| module_index.get(m).copied())
+                .map(|idx| cluster_of[idx])
+                .collect();
+            clusters.sort_unstable();
+            clusters.dedup();
+            if clusters.len() < 2 {
+                continue;
+            }
+            coarse.add_net(net.clone());
+            net_weight.insert(net.clone(), self.weight_of_net(net));
+            for cluster in clusters {
+                coarse.add_edge(net, &format!("c{cluster}"));
+            }
+        }
+        coarse.net_weight = Some(net_weight);
+
+        (coarse, cluster_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_chain_netlist() -> Netlist {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m0".to_string());
+        netlist.add_module("m1".to_string());
+        netlist.add_module("m2".to_string());
+        netlist.add_module("m3".to_string());
+        netlist.add_net("n0".to_string());
+        netlist.add_net("n1".to_string());
+        netlist.add_net("n2".to_string());
+        netlist.add_edge("n0", "m0");
+        netlist.add_edge("n0", "m1");
+        netlist.add_edge("n1", "m1");
+        netlist.add_edge("n1", "m2");
+        netlist.add_edge("n2", "m2");
+        netlist.add_edge("n2", "m3");
+        netlist
+    }
+
+    #[test]
+    fn test_coarsen_halves_modules() {
+        let netlist = build_chain_netlist();
+        let (coarse, cluster_of) = netlist.coarsen();
+        assert_eq!(cluster_of.len(), 4);
+        assert_eq!(coarse.num_modules, 2);
+        let total_weight: i32 = coarse.module_weight.as_ref().unwrap().values().sum();
+        assert_eq!(total_weight, 4);
+    }
+
+    #[test]
+    fn test_coarsen_drops_internal_net() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m0".to_string());
+        netlist.add_module("m1".to_string());
+        netlist.add_net("n0".to_string());
+        netlist.add_edge("n0", "m0");
+        netlist.add_edge("n0", "m1");
+
+        let (coarse, _cluster_of) = netlist.coarsen();
+        assert_eq!(coarse.num_nets, 0);
+    }
+}
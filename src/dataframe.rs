@@ -0,0 +1,135 @@
+#![cfg(feature = "polars")]
+
+use crate::netlist::Netlist;
+use polars::prelude::*;
+use std::collections::HashMap;
+
+impl Netlist {
+    /// Bulk-constructs a `Netlist` from columnar module/net/pin data.
+    ///
+    /// `modules` and `nets` must each carry an `"id"` column and may carry
+    /// an optional `"weight"` column; `pins` carries `"net"`/`"module"`
+    /// columns defining the incidences between them. `modules`, `nets` and
+    /// the weight maps are filled from the columns; each pin row with both
+    /// a net and a module is then added through one `add_edge` call, so
+    /// there is no separate bulk path for building `grph`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use netlistx_rs::netlist::Netlist;
+    /// use polars::prelude::*;
+    ///
+    /// let modules = df!["id" => ["m1", "m2"]].unwrap();
+    /// let nets = df!["id" => ["n1"]].unwrap();
+    /// let pins = df!["net" => ["n1", "n1"], "module" => ["m1", "m2"]].unwrap();
+    ///
+    /// let netlist = Netlist::from_dataframes(&modules, &nets, &pins).unwrap();
+    /// assert_eq!(netlist.num_modules, 2);
+    /// ```
+    pub fn from_dataframes(
+        modules: &DataFrame,
+        nets: &DataFrame,
+        pins: &DataFrame,
+    ) -> PolarsResult<Netlist> {
+        let mut netlist = Netlist::new();
+
+        let module_ids = modules.column("id")?.str()?;
+        for id in module_ids.into_iter().flatten() {
+            netlist.add_module(id.to_string());
+        }
+        if let Ok(column) = modules.column("weight") {
+            // Cast rather than assume `Int32`: Polars infers `Int64` for
+            // plain integer columns from CSV/Parquet/Rust literals, and
+            // `.i32()` would error out on that far more common case.
+            let weights = column.cast(&DataType::Int32)?;
+            let weights = weights.i32()?;
+            let mut module_weight = HashMap::new();
+            for (id, weight) in module_ids.into_iter().flatten().zip(weights) {
+                if let Some(weight) = weight {
+                    module_weight.insert(id.to_string(), weight);
+                }
+            }
+            netlist.module_weight = Some(module_weight);
+        }
+
+        let net_ids = nets.column("id")?.str()?;
+        for id in net_ids.into_iter().flatten() {
+            netlist.add_net(id.to_string());
+        }
+        if let Ok(column) = nets.column("weight") {
+            let weights = column.cast(&DataType::Int32)?;
+            let weights = weights.i32()?;
+            let mut net_weight = HashMap::new();
+            for (id, weight) in net_ids.into_iter().flatten().zip(weights) {
+                if let Some(weight) = weight {
+                    net_weight.insert(id.to_string(), weight);
+                }
+            }
+            netlist.net_weight = Some(net_weight);
+        }
+
+        let pin_nets = pins.column("net")?.str()?;
+        let pin_modules = pins.column("module")?.str()?;
+        for (net, module) in pin_nets.into_iter().zip(pin_modules) {
+            if let (Some(net), Some(module)) = (net, module) {
+                netlist.add_edge(net, module);
+            }
+        }
+
+        Ok(netlist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_dataframes_builds_incidences() {
+        let modules = df!["id" => ["m1", "m2"]].unwrap();
+        let nets = df!["id" => ["n1"]].unwrap();
+        let pins = df![
+            "net" => ["n1", "n1"],
+            "module" => ["m1", "m2"],
+        ]
+        .unwrap();
+
+        let netlist = Netlist::from_dataframes(&modules, &nets, &pins).unwrap();
+        assert_eq!(netlist.num_modules, 2);
+        assert_eq!(netlist.num_nets, 1);
+        assert_eq!(netlist.grph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_from_dataframes_reads_weights() {
+        let modules = df!["id" => ["m1", "m2"], "weight" => [3i32, 5i32]].unwrap();
+        let nets = df!["id" => ["n1"], "weight" => [2i32]].unwrap();
+        let pins = df![
+            "net" => ["n1", "n1"],
+            "module" => ["m1", "m2"],
+        ]
+        .unwrap();
+
+        let netlist = Netlist::from_dataframes(&modules, &nets, &pins).unwrap();
+        assert_eq!(netlist.module_weight.as_ref().unwrap()["m1"], 3);
+        assert_eq!(netlist.net_weight.as_ref().unwrap()["n1"], 2);
+    }
+
+    #[test]
+    fn test_from_dataframes_accepts_int64_weights() {
+        // Polars infers `Int64` for plain integer literals/CSV columns, not
+        // `Int32` — this is the shape real Parquet/CSV ingestion hands us.
+        let modules = df!["id" => ["m1", "m2"], "weight" => [3i64, 5i64]].unwrap();
+        let nets = df!["id" => ["n1"], "weight" => [2i64]].unwrap();
+        let pins = df![
+            "net" => ["n1", "n1"],
+            "module" => ["m1", "m2"],
+        ]
+        .unwrap();
+
+        let netlist = Netlist::from_dataframes(&modules, &nets, &pins).unwrap();
+        assert_eq!(netlist.module_weight.as_ref().unwrap()["m1"], 3);
+        assert_eq!(netlist.net_weight.as_ref().unwrap()["n1"], 2);
+    }
+}
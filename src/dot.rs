@@ -0,0 +1,84 @@
+use crate::netlist::Netlist;
+use petgraph::dot::{Config, Dot};
+use std::collections::HashSet;
+
+impl Netlist {
+    /// Renders the bipartite module/net incidence graph as GraphViz Dot source.
+    ///
+    /// Modules are drawn as boxes and nets as ellipses, so the shape alone
+    /// tells the two kinds of node in `grph` apart (the raw `Debug` output of
+    /// a `petgraph::Graph` cannot, since both are stored as plain `String`
+    /// nodes with `()` edges). Fixed modules (from `module_fixed`) are filled
+    /// in a distinct color, and any known `module_weight`/`net_weight` is
+    /// appended to the node's label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_net("n1".to_string());
+    /// netlist.add_edge("n1", "m1");
+    ///
+    /// let dot = netlist.to_dot();
+    /// assert!(dot.contains("shape=box"));
+    /// assert!(dot.contains("shape=ellipse"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let module_names: HashSet<&str> = self.modules.iter().map(|s| s.as_str()).collect();
+
+        let get_node_attr = |_graph: &petgraph::Graph<String, ()>, node: (petgraph::graph::NodeIndex, &String)| {
+            let (_, name) = node;
+            if module_names.contains(name.as_str()) {
+                let mut attrs = String::from("shape=box");
+                if self.module_fixed.contains(name) {
+                    attrs.push_str(", style=filled, fillcolor=lightblue");
+                }
+                if let Some(weight) = self.module_weight.as_ref().and_then(|w| w.get(name)) {
+                    attrs.push_str(&format!(", label=\"{name} ({weight})\""));
+                }
+                attrs
+            } else {
+                let mut attrs = String::from("shape=ellipse");
+                if let Some(weight) = self.net_weight.as_ref().and_then(|w| w.get(name)) {
+                    attrs.push_str(&format!(", label=\"{name} ({weight})\""));
+                }
+                attrs
+            }
+        };
+        let get_edge_attr = |_graph: &petgraph::Graph<String, ()>, _edge: petgraph::graph::EdgeReference<()>| {
+            String::new()
+        };
+
+        format!(
+            "{:?}",
+            Dot::with_attr_getters(
+                &self.grph,
+                &[Config::NodeNoLabel, Config::EdgeNoLabel],
+                &get_edge_attr,
+                &get_node_attr,
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dot_distinguishes_modules_and_nets() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.add_net("n1".to_string());
+        netlist.add_edge("n1", "m1");
+        netlist.module_fixed.insert("m1".to_string());
+
+        let dot = netlist.to_dot();
+        assert!(dot.contains("shape=box"));
+        assert!(dot.contains("shape=ellipse"));
+        assert!(dot.contains("fillcolor=lightblue"));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::netlist::Netlist;
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Write};
+
+fn parse_field<T: std::str::FromStr>(token: Option<&str>, what: &str) -> io::Result<T> {
+    token
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("missing {what}")))?
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("invalid {what}")))
+}
+
+impl Netlist {
+    /// Reads a hypergraph in the hMETIS/DIMACS text format into a new `Netlist`.
+    ///
+    /// The format is a header line `<num_nets> <num_modules> [fmt]`, followed
+    /// by one line per net listing its incident 1-based module ids (optionally
+    /// prefixed with a net weight), and, if `fmt` flags vertex weights, one
+    /// trailing line per module giving its weight. `fmt` is the usual hMETIS
+    /// two-bit code: `1` for net weights, `10` for module weights, `11` for
+    /// both. Modules and nets are named `"m<id>"` / `"n<id>"` using their
+    /// 1-based position in the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let text = "2 3\n1 2\n2 3\n";
+    /// let netlist = Netlist::from_hmetis_reader(text.as_bytes()).unwrap();
+    /// assert_eq!(netlist.num_nets, 2);
+    /// assert_eq!(netlist.num_modules, 3);
+    /// ```
+    pub fn from_hmetis_reader<R: Read>(reader: R) -> io::Result<Netlist> {
+        let mut lines = io::BufReader::new(reader).lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "missing header line"))??;
+        let mut header_tokens = header.split_whitespace();
+        let num_nets: usize = parse_field(header_tokens.next(), "num_nets")?;
+        let num_modules: usize = parse_field(header_tokens.next(), "num_modules")?;
+        let fmt: u32 = match header_tokens.next() {
+            Some(tok) => parse_field(Some(tok), "fmt")?,
+            None => 0,
+        };
+        let has_net_weights = fmt == 1 || fmt == 11;
+        let has_module_weights = fmt == 10 || fmt == 11;
+
+        let mut netlist = Netlist::new();
+        for i in 1..=num_modules {
+            netlist.add_module(format!("m{i}"));
+        }
+
+        let mut net_weight: HashMap<String, i32> = HashMap::new();
+        for net_idx in 1..=num_nets {
+            let line = lines.next().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "missing net line")
+            })??;
+            let net_name = format!("n{net_idx}");
+            netlist.add_net(net_name.clone());
+            let mut tokens = line.split_whitespace();
+            if has_net_weights {
+                let weight: i32 = parse_field(tokens.next(), "net weight")?;
+                net_weight.insert(net_name.clone(), weight);
+            }
+            for tok in tokens {
+                let module_id: usize = tok
+                    .parse()
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid module id"))?;
+                netlist.add_edge(&net_name, &format!("m{module_id}"));
+            }
+        }
+        if has_net_weights {
+            netlist.net_weight = Some(net_weight);
+        }
+
+        if has_module_weights {
+            let mut module_weight: HashMap<String, i32> = HashMap::new();
+            for i in 1..=num_modules {
+                let line = lines.next().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::UnexpectedEof, "missing module weight line")
+                })??;
+                let weight: i32 = line
+                    .split_whitespace()
+                    .next()
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing weight"))?
+                    .parse()
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid weight"))?;
+                module_weight.insert(format!("m{i}"), weight);
+            }
+            netlist.module_weight = Some(module_weight);
+        }
+
+        Ok(netlist)
+    }
+
+    /// Writes the netlist out in hMETIS/DIMACS hypergraph text format.
+    ///
+    /// This is the inverse of [`Netlist::from_hmetis_reader`]: the header's
+    /// `fmt` field is derived from whether `net_weight`/`module_weight` are
+    /// set, and modules are written in their `self.modules` order (1-based).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_module("m2".to_string());
+    /// netlist.add_net("n1".to_string());
+    /// netlist.add_edge("n1", "m1");
+    /// netlist.add_edge("n1", "m2");
+    ///
+    /// let mut out = Vec::new();
+    /// netlist.to_hmetis_writer(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "1 2\n1 2\n");
+    /// ```
+    pub fn to_hmetis_writer<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let has_net_weights = self.net_weight.is_some();
+        let has_module_weights = self.module_weight.is_some();
+        match (has_net_weights, has_module_weights) {
+            (true, true) => writeln!(writer, "{} {} 11", self.num_nets, self.num_modules)?,
+            (true, false) => writeln!(writer, "{} {} 1", self.num_nets, self.num_modules)?,
+            (false, true) => writeln!(writer, "{} {} 10", self.num_nets, self.num_modules)?,
+            (false, false) => writeln!(writer, "{} {}", self.num_nets, self.num_modules)?,
+        }
+
+        let module_id: HashMap<&str, usize> = self
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.as_str(), i + 1))
+            .collect();
+
+        for net in &self.nets {
+            let mut parts = Vec::new();
+            if has_net_weights {
+                let weight = self
+                    .net_weight
+                    .as_ref()
+                    .and_then(|w| w.get(net))
+                    .copied()
+                    .unwrap_or(1);
+                parts.push(weight.to_string());
+            }
+            // `modules_of_net` walks petgraph's adjacency list, which yields
+            // edges in reverse insertion order, not the order `add_edge` was
+            // called in. Sort by 1-based module id so the written order is
+            // deterministic and round-trips the file this netlist was read
+            // from instead of depending on petgraph's internal edge order.
+            let mut incident_ids: Vec<usize> = self
+                .modules_of_net(net)
+                .filter_map(|module| module_id.get(module).copied())
+                .collect();
+            incident_ids.sort_unstable();
+            for id in incident_ids {
+                parts.push(id.to_string());
+            }
+            writeln!(writer, "{}", parts.join(" "))?;
+        }
+
+        if has_module_weights {
+            for module in &self.modules {
+                let weight = self
+                    .module_weight
+                    .as_ref()
+                    .and_then(|w| w.get(module))
+                    .copied()
+                    .unwrap_or(1);
+                writeln!(writer, "{weight}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_unweighted() {
+        let text = "2 3\n1 2\n2 3\n";
+        let netlist = Netlist::from_hmetis_reader(text.as_bytes()).unwrap();
+        assert_eq!(netlist.num_nets, 2);
+        assert_eq!(netlist.num_modules, 3);
+        assert_eq!(netlist.grph.edge_count(), 4);
+
+        let mut out = Vec::new();
+        netlist.to_hmetis_writer(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), text);
+    }
+
+    #[test]
+    fn test_parse_with_weights() {
+        let text = "1 2 11\n5 1 2\n7\n9\n";
+        let netlist = Netlist::from_hmetis_reader(text.as_bytes()).unwrap();
+        assert_eq!(netlist.net_weight.as_ref().unwrap()["n1"], 5);
+        assert_eq!(netlist.module_weight.as_ref().unwrap()["m1"], 7);
+        assert_eq!(netlist.module_weight.as_ref().unwrap()["m2"], 9);
+    }
+}
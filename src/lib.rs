@@ -0,0 +1,8 @@
+pub mod attr;
+pub mod coarsen;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+pub mod dot;
+pub mod hmetis;
+pub mod netlist;
+pub mod pattern;
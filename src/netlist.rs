@@ -1,3 +1,5 @@
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
 use petgraph::Graph;
 use std::collections::{HashMap, HashSet};
 
@@ -19,6 +21,17 @@ pub struct Netlist {
     pub module_fixed: HashSet<String>,
     pub max_degree: u32,
     pub max_net_degree: u32,
+    /// Cached lookup from module/net name to its node index in `grph`, kept
+    /// in sync by `add_module`/`add_net` so `add_edge` and the adjacency
+    /// queries below don't need to rescan `node_indices()`.
+    node_index: HashMap<String, NodeIndex>,
+    /// Typed per-module attributes (cell type, fixed coordinate, timing, ...)
+    /// beyond an integer `"weight"` attribute, which is served from
+    /// `module_weight` instead; a non-integer `"weight"` is kept here. See the
+    /// `attr` module for the accessors.
+    pub(crate) module_attrs: HashMap<String, HashMap<String, crate::attr::Value>>,
+    /// Typed per-net attributes, analogous to `module_attrs`.
+    pub(crate) net_attrs: HashMap<String, HashMap<String, crate::attr::Value>>,
 }
 
 impl Netlist {
@@ -47,6 +60,9 @@ impl Netlist {
             module_fixed: HashSet::new(),
             max_degree: 0,
             max_net_degree: 0,
+            node_index: HashMap::new(),
+            module_attrs: HashMap::new(),
+            net_attrs: HashMap::new(),
         }
     }
 
@@ -62,8 +78,9 @@ impl Netlist {
     /// assert_eq!(netlist.num_modules, 1);
     /// ```
     pub fn add_module(&mut self, module: String) {
-        self.modules.push(module.clone());
-        self.grph.add_node(module);
+        let index = self.grph.add_node(module.clone());
+        self.node_index.insert(module.clone(), index);
+        self.modules.push(module);
         self.num_modules = self.modules.len();
     }
 
@@ -79,8 +96,9 @@ impl Netlist {
     /// assert_eq!(netlist.num_nets, 1);
     /// ```
     pub fn add_net(&mut self, net: String) {
-        self.nets.push(net.clone());
-        self.grph.add_node(net);
+        let index = self.grph.add_node(net.clone());
+        self.node_index.insert(net.clone(), index);
+        self.nets.push(net);
         self.num_nets = self.nets.len();
     }
 
@@ -98,12 +116,66 @@ impl Netlist {
     /// assert_eq!(netlist.grph.edge_count(), 1);
     /// ```
     pub fn add_edge(&mut self, net: &str, module: &str) {
-        let net_index = self.grph.node_indices().find(|i| self.grph[*i] == net);
-        let module_index = self.grph.node_indices().find(|i| self.grph[*i] == module);
+        let net_index = self.node_index.get(net).copied();
+        let module_index = self.node_index.get(module).copied();
         if let (Some(net_index), Some(module_index)) = (net_index, module_index) {
             self.grph.add_edge(net_index, module_index, ());
+            self.max_net_degree = self.max_net_degree.max(self.net_degree(net));
+            self.max_degree = self.max_degree.max(self.module_degree(module));
         }
     }
+
+    /// Returns the names of the modules incident to `net`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_net("n1".to_string());
+    /// netlist.add_edge("n1", "m1");
+    /// assert_eq!(netlist.modules_of_net("n1").collect::<Vec<_>>(), vec!["m1"]);
+    /// ```
+    pub fn modules_of_net<'a>(&'a self, net: &str) -> impl Iterator<Item = &'a str> {
+        self.node_index
+            .get(net)
+            .copied()
+            .into_iter()
+            .flat_map(move |index| self.grph.neighbors(index).map(move |n| self.grph[n].as_str()))
+    }
+
+    /// Returns the names of the nets incident to `module`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_net("n1".to_string());
+    /// netlist.add_edge("n1", "m1");
+    /// assert_eq!(netlist.nets_of_module("m1").collect::<Vec<_>>(), vec!["n1"]);
+    /// ```
+    pub fn nets_of_module<'a>(&'a self, module: &str) -> impl Iterator<Item = &'a str> {
+        self.node_index.get(module).copied().into_iter().flat_map(move |index| {
+            self.grph
+                .neighbors_directed(index, Direction::Incoming)
+                .map(move |n| self.grph[n].as_str())
+        })
+    }
+
+    /// Returns the number of nets incident to `module`.
+    pub fn module_degree(&self, module: &str) -> u32 {
+        self.nets_of_module(module).count() as u32
+    }
+
+    /// Returns the number of modules incident to `net`.
+    pub fn net_degree(&self, net: &str) -> u32 {
+        self.modules_of_net(net).count() as u32
+    }
 }
 
 impl Default for Netlist {
@@ -134,10 +206,21 @@ mod tests {
         let mut grph = Graph::new();
         let a0 = grph.add_node("a0".to_string());
         let a1 = grph.add_node("a1".to_string());
-        let _a2 = grph.add_node("a2".to_string());
+        let a2 = grph.add_node("a2".to_string());
         let a3 = grph.add_node("a3".to_string());
-        let _a4 = grph.add_node("a4".to_string());
+        let a4 = grph.add_node("a4".to_string());
         let a5 = grph.add_node("a5".to_string());
+        let node_index: HashMap<String, NodeIndex> = [
+            ("a0".to_string(), a0),
+            ("a1".to_string(), a1),
+            ("a2".to_string(), a2),
+            ("a3".to_string(), a3),
+            ("a4".to_string(), a4),
+            ("a5".to_string(), a5),
+        ]
+        .iter()
+        .cloned()
+        .collect();
         let module_weight: HashMap<String, i32> = [
             ("a0".to_string(), 533),
             ("a1".to_string(), 543),
@@ -162,6 +245,9 @@ mod tests {
             module_fixed: HashSet::new(),
             max_degree: 0,
             max_net_degree: 0,
+            node_index,
+            module_attrs: HashMap::new(),
+            net_attrs: HashMap::new(),
         };
         hyprgraph.module_weight = Some(module_weight);
         hyprgraph
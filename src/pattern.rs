@@ -0,0 +1,178 @@
+use crate::netlist::Netlist;
+use std::collections::{HashMap, HashSet};
+
+impl Netlist {
+    /// Finds all occurrences of `pattern` inside `self`, returning one
+    /// module-to-module (and net-to-net) name mapping per match.
+    ///
+    /// The search respects the module/net bipartition of the netlist: a
+    /// pattern module may only map to a host module, and a pattern net only
+    /// to a host net. A mapping is accepted when every incidence in
+    /// `pattern` has a corresponding incidence in `self` (a subgraph
+    /// isomorphism, not necessarily induced), each host node has at least as
+    /// high a degree as its pattern counterpart, and, when the pattern sets
+    /// a `module_weight` for a module, the matched host module has the same
+    /// weight.
+    ///
+    /// This is a plain backtracking search and is only intended for small
+    /// pattern netlists such as a cell or standard-structure template.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use netlistx_rs::netlist::Netlist;
+    ///
+    /// let mut netlist = Netlist::new();
+    /// netlist.add_module("m1".to_string());
+    /// netlist.add_module("m2".to_string());
+    /// netlist.add_net("n1".to_string());
+    /// netlist.add_edge("n1", "m1");
+    /// netlist.add_edge("n1", "m2");
+    ///
+    /// let mut pattern = Netlist::new();
+    /// pattern.add_module("p0".to_string());
+    /// pattern.add_net("q0".to_string());
+    /// pattern.add_edge("q0", "p0");
+    ///
+    /// let matches = netlist.find_pattern(&pattern);
+    /// assert_eq!(matches.len(), 2);
+    /// ```
+    pub fn find_pattern(&self, pattern: &Netlist) -> Vec<HashMap<String, String>> {
+        let nodes: Vec<(&str, bool)> = pattern
+            .modules
+            .iter()
+            .map(|m| (m.as_str(), true))
+            .chain(pattern.nets.iter().map(|n| (n.as_str(), false)))
+            .collect();
+
+        let mut results = Vec::new();
+        let mut mapping = HashMap::new();
+        let mut used = HashSet::new();
+        self.search(pattern, &nodes, 0, &mut mapping, &mut used, &mut results);
+        results
+    }
+
+    fn search(
+        &self,
+        pattern: &Netlist,
+        nodes: &[(&str, bool)],
+        depth: usize,
+        mapping: &mut HashMap<String, String>,
+        used: &mut HashSet<String>,
+        results: &mut Vec<HashMap<String, String>>,
+    ) {
+        let Some(&(pat_name, is_module)) = nodes.get(depth) else {
+            results.push(mapping.clone());
+            return;
+        };
+
+        let candidates: &[String] = if is_module { &self.modules } else { &self.nets };
+        for host_name in candidates {
+            if used.contains(host_name) {
+                continue;
+            }
+            if !self.is_compatible(pattern, pat_name, is_module, host_name, mapping) {
+                continue;
+            }
+            mapping.insert(pat_name.to_string(), host_name.clone());
+            used.insert(host_name.clone());
+            self.search(pattern, nodes, depth + 1, mapping, used, results);
+            mapping.remove(pat_name);
+            used.remove(host_name);
+        }
+    }
+
+    fn is_compatible(
+        &self,
+        pattern: &Netlist,
+        pat_name: &str,
+        is_module: bool,
+        host_name: &str,
+        mapping: &HashMap<String, String>,
+    ) -> bool {
+        if is_module {
+            if pattern.module_degree(pat_name) > self.module_degree(host_name) {
+                return false;
+            }
+            if let Some(pattern_weight) = pattern.module_weight.as_ref().and_then(|w| w.get(pat_name)) {
+                if self.module_weight.as_ref().and_then(|w| w.get(host_name)) != Some(pattern_weight) {
+                    return false;
+                }
+            }
+            // `nodes` in `find_pattern` lists modules before nets, so when a
+            // module candidate is checked here, `mapping` cannot yet hold any
+            // net key; the incidence check against already-mapped nets that
+            // `search` relies on instead happens from the net side below.
+            true
+        } else {
+            if pattern.net_degree(pat_name) > self.net_degree(host_name) {
+                return false;
+            }
+            pattern.modules_of_net(pat_name).all(|pat_module| {
+                mapping
+                    .get(pat_module)
+                    .is_none_or(|host_module| self.modules_of_net(host_name).any(|m| m == host_module))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_pin_pattern() -> Netlist {
+        let mut pattern = Netlist::new();
+        pattern.add_module("p0".to_string());
+        pattern.add_net("q0".to_string());
+        pattern.add_edge("q0", "p0");
+        pattern
+    }
+
+    #[test]
+    fn test_find_pattern_matches_every_incident_pair() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.add_module("m2".to_string());
+        netlist.add_net("n1".to_string());
+        netlist.add_edge("n1", "m1");
+        netlist.add_edge("n1", "m2");
+
+        let matches = netlist.find_pattern(&single_pin_pattern());
+        assert_eq!(matches.len(), 2);
+        assert!(matches
+            .iter()
+            .any(|m| m["p0"] == "m1" && m["q0"] == "n1"));
+        assert!(matches
+            .iter()
+            .any(|m| m["p0"] == "m2" && m["q0"] == "n1"));
+    }
+
+    #[test]
+    fn test_find_pattern_respects_module_weight_constraint() {
+        let mut netlist = Netlist::new();
+        netlist.add_module("m1".to_string());
+        netlist.add_module("m2".to_string());
+        netlist.add_net("n1".to_string());
+        netlist.add_edge("n1", "m1");
+        netlist.add_edge("n1", "m2");
+        netlist.module_weight = Some(
+            [("m1".to_string(), 5), ("m2".to_string(), 9)]
+                .iter()
+                .cloned()
+                .collect(),
+        );
+
+        let mut pattern = single_pin_pattern();
+        pattern.module_weight = Some(
+            [("p0".to_string(), 9)]
+                .iter()
+                .cloned()
+                .collect(),
+        );
+
+        let matches = netlist.find_pattern(&pattern);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0]["p0"], "m2");
+    }
+}